@@ -0,0 +1,161 @@
+/*
+ * Copyright (c) Radical HQ Limited
+ *
+ * This source code is licensed under the MIT license found in the
+ * LICENSE file in the root directory of this source tree.
+ */
+
+use std::collections::HashSet;
+
+/// Locations where GitHub looks for a CODEOWNERS file, in order of
+/// precedence.
+pub const CODEOWNERS_PATHS: &[&str] =
+    &[".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Suggest reviewers for a change touching the given paths, based on the
+/// rules in a CODEOWNERS file. As with GitHub, the last matching rule for a
+/// path determines its owners. Users are returned as plain logins and teams
+/// in the `#team` form used in the Reviewers section. Owners given by email
+/// address are ignored.
+pub fn suggest_reviewers(
+    changed_paths: &[String],
+    codeowners: &str,
+) -> Vec<String> {
+    let rules: Vec<(lazy_regex::Regex, Vec<&str>)> = codeowners
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let mut tokens = line.split_whitespace();
+            let pattern = pattern_to_regex(tokens.next()?)?;
+            Some((pattern, tokens.collect()))
+        })
+        .collect();
+
+    let mut seen = HashSet::new();
+    let mut result = Vec::new();
+
+    for path in changed_paths {
+        let owners = rules
+            .iter()
+            .rev()
+            .find(|(pattern, _)| pattern.is_match(path))
+            .map(|(_, owners)| &owners[..])
+            .unwrap_or_default();
+
+        for owner in owners {
+            let reviewer = match owner.strip_prefix('@') {
+                Some(handle) => match handle.split_once('/') {
+                    Some((_org, team)) => format!("#{}", team),
+                    None => handle.to_string(),
+                },
+                None => continue,
+            };
+
+            if seen.insert(reviewer.to_ascii_lowercase()) {
+                result.push(reviewer);
+            }
+        }
+    }
+
+    result
+}
+
+fn pattern_to_regex(pattern: &str) -> Option<lazy_regex::Regex> {
+    let directory_only = pattern.ends_with('/');
+    let pattern = pattern.trim_end_matches('/');
+    // Patterns containing a slash other than at the end are relative to the
+    // repository root, all others match at any depth.
+    let anchored = pattern.contains('/');
+    let pattern = pattern.trim_start_matches('/');
+
+    let mut regex = String::from(if anchored { "^" } else { "^(?:.*/)?" });
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => {
+                if r"\.+*?()|[]{}^$".contains(c) {
+                    regex.push('\\');
+                }
+                regex.push(c);
+            }
+        }
+    }
+    // A pattern naming a directory owns everything below it.
+    regex.push_str(if directory_only { "/.*$" } else { "(?:/.*)?$" });
+
+    lazy_regex::Regex::new(&regex).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    const CODEOWNERS: &str = r#"
+# Default owners
+*       @alice
+
+*.rs    @bob @acme/rustaceans
+/docs/  @carol
+build/  carol@example.com
+"#;
+
+    fn paths(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn test_suggest_reviewers_last_match_wins() {
+        assert_eq!(
+            suggest_reviewers(&paths(&["README.md"]), CODEOWNERS),
+            vec!["alice".to_string()]
+        );
+        assert_eq!(
+            suggest_reviewers(&paths(&["spr/src/main.rs"]), CODEOWNERS),
+            vec!["bob".to_string(), "#rustaceans".to_string()]
+        );
+        assert_eq!(
+            suggest_reviewers(&paths(&["docs/user/setup.md"]), CODEOWNERS),
+            vec!["carol".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_suggest_reviewers_dedup() {
+        assert_eq!(
+            suggest_reviewers(
+                &paths(&["a.rs", "b/c.rs", "README.md"]),
+                CODEOWNERS
+            ),
+            vec![
+                "bob".to_string(),
+                "#rustaceans".to_string(),
+                "alice".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_suggest_reviewers_no_match() {
+        assert!(
+            suggest_reviewers(&paths(&["src/docs/x.md"]), "/docs/ @carol")
+                .is_empty()
+        );
+        assert!(
+            suggest_reviewers(&paths(&["build/out"]), CODEOWNERS).is_empty()
+        );
+        assert!(suggest_reviewers(&paths(&["x"]), "").is_empty());
+    }
+}
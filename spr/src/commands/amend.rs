@@ -8,7 +8,10 @@
 use crate::{
     error::{Error, Result},
     git::PreparedCommit,
-    message::validate_commit_message,
+    message::{
+        describe_section_changes, diff_sections, validate_commit_message,
+        with_local_only_sections,
+    },
     output::{output, write_commit_title},
 };
 
@@ -59,12 +62,25 @@ pub async fn amend(
         let pull_request = pull_requests.pop().flatten();
         if let Some(pull_request) = pull_request {
             let pull_request = pull_request.await??;
-            commit.message = pull_request.sections;
+            let sections = with_local_only_sections(
+                pull_request.sections,
+                &commit.message,
+                config,
+            );
+            let changes = diff_sections(&commit.message, &sections);
+            if !changes.is_empty() {
+                output(
+                    &config.output_symbols.info,
+                    &describe_section_changes(&changes, config),
+                )?;
+            }
+            commit.message = sections;
         }
-        failure = validate_commit_message(&commit.message, config).is_err()
+        failure = validate_commit_message(&commit.message, config, false)
+            .is_err()
             || failure;
     }
-    git.rewrite_commit_messages(slice, None)?;
+    git.rewrite_commit_messages(config, slice, None)?;
 
     if failure {
         Err(Error::empty())
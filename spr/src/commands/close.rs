@@ -13,7 +13,7 @@ use crate::{
     error::{add_error, Error, Result},
     git::PreparedCommit,
     github::{PullRequestState, PullRequestUpdate},
-    message::MessageSection,
+    message::strip_managed_sections,
     output::{output, write_commit_title},
 };
 
@@ -63,7 +63,11 @@ pub async fn close(
     // changed by the implementation)
     add_error(
         &mut result,
-        git.rewrite_commit_messages(prepared_commits.as_mut_slice(), None),
+        git.rewrite_commit_messages(
+            config,
+            prepared_commits.as_mut_slice(),
+            None,
+        ),
     );
 
     result
@@ -110,7 +114,10 @@ async fn close_impl(
     match result {
         Ok(()) => (),
         Err(error) => {
-            output("❌", "GitHub Pull Request close failed")?;
+            output(
+                &config.output_symbols.error,
+                "GitHub Pull Request close failed",
+            )?;
 
             return Err(error);
         }
@@ -119,8 +126,7 @@ async fn close_impl(
     output("📕", "Closed!")?;
 
     // Remove sections from commit that are not relevant after closing.
-    prepared_commit.message.remove(&MessageSection::PullRequest);
-    prepared_commit.message.remove(&MessageSection::ReviewedBy);
+    strip_managed_sections(&mut prepared_commit.message, true);
 
     let mut remove_old_branch_child_process =
         tokio::process::Command::new("git")
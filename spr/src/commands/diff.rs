@@ -8,15 +8,23 @@
 use std::iter::zip;
 
 use crate::{
+    codeowners::{suggest_reviewers, CODEOWNERS_PATHS},
     error::{add_error, Error, Result, ResultExt},
     git::PreparedCommit,
     github::{
         GitHub, PullRequest, PullRequestRequestReviewers, PullRequestState,
         PullRequestUpdate,
     },
-    message::{validate_commit_message, MessageSection},
+    message::{
+        detect_probable_typos, github_title_warning, message_section_label,
+        set_pull_request, title_matches_content, unknown_labels,
+        validate_commit_message, validate_stack_reviewers, MessageSection,
+    },
     output::{output, write_commit_title},
-    utils::{parse_name_list, remove_all_parens, run_command},
+    utils::{
+        has_mixed_reviewer_prefix, parse_reviewers, remove_all_parens,
+        run_command,
+    },
 };
 use git2::Oid;
 use indoc::{formatdoc, indoc};
@@ -47,6 +55,10 @@ pub struct DiffOptions {
     /// on any intermediate changes between the master branch and this commit.
     #[clap(long)]
     cherry_pick: bool,
+
+    /// Report more about how the commit message was read
+    #[clap(long, short = 'v')]
+    verbose: bool,
 }
 
 pub async fn diff(
@@ -65,7 +77,7 @@ pub async fn diff(
 
     // The parent of the first commit in the list is the commit on master that
     // the local branch is based on
-    let master_base_oid = if let Some(first_commit) = prepared_commits.get(0) {
+    let master_base_oid = if let Some(first_commit) = prepared_commits.first() {
         first_commit.parent_oid
     } else {
         output("👋", "Branch is empty - nothing to do. Good bye!")?;
@@ -76,6 +88,12 @@ pub async fn diff(
         // Remove all prepared commits from the vector but the last. So, if
         // `--all` is not given, we only operate on the HEAD commit.
         prepared_commits.drain(0..prepared_commits.len() - 1);
+    } else {
+        let messages: Vec<_> = prepared_commits
+            .iter()
+            .map(|pc| pc.message.clone())
+            .collect();
+        validate_stack_reviewers(&messages, config)?;
     }
 
     #[allow(clippy::needless_collect)]
@@ -90,7 +108,7 @@ pub async fn diff(
     let mut message_on_prompt = "".to_string();
 
     for (prepared_commit, pull_request_task) in
-        zip(prepared_commits.iter_mut(), pull_request_tasks.into_iter())
+        zip(prepared_commits.iter_mut(), pull_request_tasks)
     {
         if result.is_err() {
             break;
@@ -125,7 +143,11 @@ pub async fn diff(
     // changed by the implementation)
     add_error(
         &mut result,
-        git.rewrite_commit_messages(prepared_commits.as_mut_slice(), None),
+        git.rewrite_commit_messages(
+            config,
+            prepared_commits.as_mut_slice(),
+            None,
+        ),
     );
 
     result
@@ -196,7 +218,58 @@ async fn diff_impl(
     }
 
     if local_commit.pull_request_number.is_none() || opts.update_message {
-        validate_commit_message(message, config)?;
+        validate_commit_message(message, config, false)
+            .map_err(|_| Error::empty())?;
+
+        if config.subject_forbid_trailing_whitespace
+            && local_commit.subject_has_trailing_whitespace
+        {
+            output(
+                &config.output_symbols.error,
+                "Commit message title ends in whitespace!",
+            )?;
+            return Err(Error::empty());
+        }
+
+        if opts.verbose {
+            let unknown = unknown_labels(message, config);
+            if !unknown.is_empty() {
+                output(
+                    &config.output_symbols.info,
+                    &format!(
+                        "Keeping lines with unknown labels as text: {}",
+                        unknown.join(", ")
+                    ),
+                )?;
+            }
+        }
+
+        if let Some(warning) = github_title_warning(message, config) {
+            output(&config.output_symbols.warning, &warning)?;
+        }
+
+        for (label, section) in detect_probable_typos(message, config) {
+            output(
+                &config.output_symbols.warning,
+                &format!(
+                    "'{}:' is not a section spr knows; did you mean '{}:'?",
+                    label,
+                    message_section_label(&section, config)
+                ),
+            )?;
+        }
+    }
+
+    if let Some(ref pull_request) = pull_request {
+        if opts.update_message
+            && !title_matches_content(message, &pull_request.sections)
+        {
+            output(
+                &config.output_symbols.warning,
+                "The summary changed substantially, but the title did not. \
+                 Consider updating the title, too.",
+            )?;
+        }
     }
 
     if let Some(ref pull_request) = pull_request {
@@ -210,11 +283,11 @@ async fn diff_impl(
         if !opts.update_message {
             let mut pull_request_updates: PullRequestUpdate =
                 Default::default();
-            pull_request_updates.update_message(pull_request, message);
+            pull_request_updates.update_message(pull_request, message, config);
 
             if !pull_request_updates.is_empty() {
                 output(
-                    "⚠️",
+                    &config.output_symbols.warning,
                     indoc!(
                         "The Pull Request's title/message differ from the \
                          local commit's message.
@@ -233,7 +306,16 @@ async fn diff_impl(
 
     if local_commit.pull_request_number.is_none() {
         if let Some(reviewers) = message.get(&MessageSection::Reviewers) {
-            let reviewers = parse_name_list(reviewers);
+            if config.warn_mixed_reviewer_prefix
+                && has_mixed_reviewer_prefix(reviewers)
+            {
+                output(
+                    &config.output_symbols.warning,
+                    "Reviewers names someone both with and without a \
+                     leading @; please pick one",
+                )?;
+            }
+            let reviewers = parse_reviewers(reviewers);
             let mut checked_reviewers = Vec::new();
 
             for reviewer in reviewers {
@@ -281,6 +363,30 @@ async fn diff_impl(
                 MessageSection::Reviewers,
                 checked_reviewers.join(", "),
             );
+        } else if config.reviewers_from_codeowners {
+            let codeowners = CODEOWNERS_PATHS
+                .iter()
+                .find_map(|path| {
+                    git.get_file_content(local_commit.oid, path).transpose()
+                })
+                .transpose()?;
+
+            if let Some(codeowners) = codeowners {
+                let suggestions = suggest_reviewers(
+                    &git.get_changed_paths(local_commit.oid)?,
+                    &codeowners,
+                );
+                if !suggestions.is_empty() {
+                    output(
+                        &config.output_symbols.info,
+                        &format!(
+                            "No reviewers given. Based on CODEOWNERS, \
+                             consider adding 'Reviewers: {}'",
+                            suggestions.join(", ")
+                        ),
+                    )?;
+                }
+            }
         }
     }
 
@@ -355,7 +461,11 @@ async fn diff_impl(
 
                 let mut pull_request_updates: PullRequestUpdate =
                     Default::default();
-                pull_request_updates.update_message(pull_request, message);
+                pull_request_updates.update_message(
+                    pull_request,
+                    message,
+                    config,
+                );
 
                 if !pull_request_updates.is_empty() {
                     // ...and there are actual changes to the message
@@ -452,17 +562,11 @@ async fn diff_impl(
 
         let new_base_branch_commit = git.create_derived_commit(
             local_commit.parent_oid,
-            &format!(
-                "{}",
-                if pull_request.is_some() {
-                    "rebase".to_string()
-                } else {
-                    format!(
-                        "changes to {}",
-                        config.master_ref.branch_name()
-                    )
-                },
-            ),
+            &if pull_request.is_some() {
+                "rebase".to_string()
+            } else {
+                format!("changes to {}", config.master_ref.branch_name())
+            },
             new_base_tree,
             &parents[..],
         )?;
@@ -513,24 +617,30 @@ async fn diff_impl(
     if let Some(oid) = pr_base_parent {
         // ...unless if that's the same commit as the one we added to
         // pr_commit_parents first.
-        if pr_commit_parents.get(0) != Some(&oid) {
+        if pr_commit_parents.first() != Some(&oid) {
             pr_commit_parents.push(oid);
         }
     }
 
-    let messages = vec!["Initial version", "wip", "lets go", "testing", "begin", "Starting..."];
-    let msg = messages.choose(&mut rand::thread_rng()).unwrap_or(&"first commit");
+    let messages = [
+        "Initial version",
+        "wip",
+        "lets go",
+        "testing",
+        "begin",
+        "Starting...",
+    ];
+    let msg = messages
+        .choose(&mut rand::thread_rng())
+        .unwrap_or(&"first commit");
 
     // Create the new commit
     let pr_commit = git.create_derived_commit(
         local_commit.oid,
-        &format!(
-            "{}",
-            github_commit_message
-                .as_ref()
-                .map(|s| &s[..])
-                .unwrap_or(msg),
-        ),
+        github_commit_message
+            .as_ref()
+            .map(|s| &s[..])
+            .unwrap_or(msg),
         new_head_tree,
         &pr_commit_parents[..],
     )?;
@@ -568,7 +678,7 @@ async fn diff_impl(
         let mut pull_request_updates: PullRequestUpdate = Default::default();
 
         if opts.update_message {
-            pull_request_updates.update_message(&pull_request, message);
+            pull_request_updates.update_message(&pull_request, message, config);
         }
 
         if let Some(base_branch) = base_branch {
@@ -650,7 +760,7 @@ async fn diff_impl(
             ),
         )?;
 
-        message.insert(MessageSection::PullRequest, pull_request_url);
+        set_pull_request(message, &pull_request_url)?;
 
         let result = gh
             .request_reviewers(pull_request_number, requested_reviewers)
@@ -658,7 +768,10 @@ async fn diff_impl(
         match result {
             Ok(()) => (),
             Err(error) => {
-                output("⚠️", "Requesting reviewers failed")?;
+                output(
+                    &config.output_symbols.warning,
+                    "Requesting reviewers failed",
+                )?;
                 for message in error.messages() {
                     output("  ", message)?;
                 }
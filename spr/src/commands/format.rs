@@ -42,10 +42,11 @@ pub async fn format(
 
     for commit in slice.iter() {
         write_commit_title(commit)?;
-        failure = validate_commit_message(&commit.message, config).is_err()
+        failure = validate_commit_message(&commit.message, config, false)
+            .is_err()
             || failure;
     }
-    git.rewrite_commit_messages(slice, None)?;
+    git.rewrite_commit_messages(config, slice, None)?;
 
     if failure {
         Err(Error::empty())
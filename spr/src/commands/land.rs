@@ -11,7 +11,10 @@ use std::{io::Write, process::Stdio, time::Duration};
 use crate::{
     error::{Error, Result, ResultExt},
     github::{PullRequestState, PullRequestUpdate, ReviewStatus},
-    message::build_github_body_for_merging,
+    message::{
+        build_github_body_for_merging, is_work_in_progress,
+        with_local_only_sections, ValidationError,
+    },
     output::{output, write_commit_title},
     utils::run_command,
 };
@@ -74,6 +77,17 @@ pub async fn land(
         )));
     }
 
+    // Work in progress may be submitted for review, but not landed. The
+    // Pull Request may also have been marked as such on GitHub.
+    if config.forbid_landing_work_in_progress
+        && (is_work_in_progress(&prepared_commit.message)
+            || is_work_in_progress(&pull_request.sections))
+    {
+        return Err(Error::new(
+            ValidationError::WorkInProgress.message(config),
+        ));
+    }
+
     if config.require_approval
         && pull_request.review_status != Some(ReviewStatus::Approved)
     {
@@ -204,9 +218,7 @@ pub async fn land(
 
             pr_head_oid = git.create_derived_commit(
                 pr_head_oid,
-                &format!(
-                    "remove any changes already in mainline",
-                ),
+                "remove any changes already in mainline",
                 our_tree_oid,
                 &[pr_head_oid, current_master],
             )?;
@@ -307,7 +319,14 @@ pub async fn land(
                 .merge(pull_request_number)
                 .method(octocrab::params::pulls::MergeMethod::Squash)
                 .title(pull_request.title)
-                .message(build_github_body_for_merging(&pull_request.sections))
+                .message(build_github_body_for_merging(
+                    &with_local_only_sections(
+                        pull_request.sections,
+                        &prepared_commit.message,
+                        config,
+                    ),
+                    config,
+                ))
                 .sha(format!("{}", pr_head_oid))
                 .send()
                 .await
@@ -329,7 +348,10 @@ pub async fn land(
     let merge = match result {
         Ok(merge) => merge,
         Err(mut error) => {
-            output("❌", "GitHub Pull Request merge failed")?;
+            output(
+                &config.output_symbols.error,
+                "GitHub Pull Request merge failed",
+            )?;
 
             // If we changed the target branch of the Pull Request earlier, then
             // undo this change now.
@@ -105,7 +105,7 @@ pub async fn patch(
         // the commit we created above to prepare the base of this commit.
         git.create_derived_commit(
             pr.head_oid,
-            &build_commit_message(&pr.sections),
+            &build_commit_message(&pr.sections, config),
             git.get_tree_oid_for_commit(pr.head_oid)?,
             &[pr_master_oid],
         )?
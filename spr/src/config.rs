@@ -5,9 +5,103 @@
  * LICENSE file in the root directory of this source tree.
  */
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
-use crate::{error::Result, github::GitHubBranch, utils::slugify};
+use crate::{
+    error::Result, github::GitHubBranch, message::MessageSection,
+    output::OutputSymbols, utils::slugify,
+};
+
+/// How commit messages are cleaned up before parsing, mirroring the values
+/// of Git's `commit.cleanup` setting. Unlike for git, the default is
+/// `Whitespace` rather than `Strip`: git already stripped comments when the
+/// commit was made, so `#` lines that are left, such as markdown headings,
+/// are part of the message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum CleanupMode {
+    /// Like `Whitespace`, but lines starting with the comment character are
+    /// removed, too.
+    Strip,
+    /// Remove leading and trailing blank lines and trailing whitespace.
+    #[default]
+    Whitespace,
+    /// Keep the message as it is, only surrounding blank lines of each
+    /// section are removed.
+    Verbatim,
+    /// Like `Strip`, but everything from the scissors line onwards is
+    /// removed.
+    Scissors,
+}
+
+impl CleanupMode {
+    /// The mode for a value of `commit.cleanup`, or `None` for a value git
+    /// does not know. `default` means the same as an unset value.
+    pub fn from_git_config(value: &str) -> Option<Self> {
+        match value {
+            "default" => Some(CleanupMode::default()),
+            "strip" => Some(CleanupMode::Strip),
+            "whitespace" => Some(CleanupMode::Whitespace),
+            "verbatim" => Some(CleanupMode::Verbatim),
+            "scissors" => Some(CleanupMode::Scissors),
+            _ => None,
+        }
+    }
+}
+
+/// Which of the messages spr generates a commit message section goes into.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct SectionDestinations {
+    /// The local commit message.
+    pub commit_message: bool,
+    /// The description of the Pull Request on GitHub.
+    pub github_body: bool,
+    /// The message of the commit created by `spr land`.
+    pub merge_body: bool,
+}
+
+impl SectionDestinations {
+    /// Where spr puts the given section unless configured otherwise.
+    pub fn default_for(section: MessageSection) -> Self {
+        use MessageSection::*;
+
+        match section {
+            Title => SectionDestinations {
+                commit_message: true,
+                github_body: false,
+                merge_body: false,
+            },
+            Summary | TestPlan | VersionBump | DependsOn | CoAuthoredBy
+            | Closes => SectionDestinations {
+                commit_message: true,
+                github_body: true,
+                merge_body: true,
+            },
+            // Internal trailers stay out of the PR description; `spr land`
+            // takes them from the local commit message.
+            SignOffChain | ChangeId => SectionDestinations {
+                commit_message: true,
+                github_body: false,
+                merge_body: true,
+            },
+            Reviewers | ReviewedBy | PullRequest => SectionDestinations {
+                commit_message: true,
+                github_body: false,
+                merge_body: true,
+            },
+            // In the commit message, the ticket is part of the title.
+            Ticket => SectionDestinations {
+                commit_message: false,
+                github_body: true,
+                merge_body: true,
+            },
+            Custom(_) => SectionDestinations {
+                commit_message: true,
+                github_body: true,
+                merge_body: true,
+            },
+        }
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct Config {
@@ -18,6 +112,64 @@ pub struct Config {
     pub branch_prefix: String,
     pub require_approval: bool,
     pub require_test_plan: bool,
+    pub trailer_case_sensitive_keys: bool,
+    pub summary_strip_html_comments: bool,
+    pub forbid_empty_summary_paragraphs: bool,
+    pub reviewers_from_codeowners: bool,
+    pub github_body_empty_placeholder: Option<String>,
+    pub max_trailer_value_length: Option<usize>,
+    pub forbid_summary_headings: bool,
+    pub summary_trim_trailing_blank_lines: bool,
+    pub require_uniform_stack_reviewers: bool,
+    pub pull_request_link_format: Option<String>,
+    pub cleanup_mode: CleanupMode,
+    pub placeholder_patterns: Vec<String>,
+    pub section_destinations: BTreeMap<MessageSection, SectionDestinations>,
+    pub suggested_title_max_length: usize,
+    pub accepted_trailer_separators: Vec<String>,
+    pub reviewed_by_include_state: bool,
+    pub subject_forbid_trailing_whitespace: bool,
+    pub section_labels: BTreeMap<MessageSection, String>,
+    pub validate_version_bump: bool,
+    pub require_reviewer_team: bool,
+    pub require_test_plan_when_summary_nonempty: bool,
+    pub title_max_words: Option<usize>,
+    pub max_subject_length: Option<usize>,
+    pub required_sections: Vec<MessageSection>,
+    pub warn_mixed_reviewer_prefix: bool,
+    pub reviewed_by_must_be_requested: bool,
+    pub normalize_on_parse: bool,
+    pub one_entry_per_line_sections: Vec<MessageSection>,
+    pub forbid_body_duplicates_title: bool,
+    pub close_issues_on_merge: bool,
+    pub align_trailer_values: bool,
+    pub footer_template: Option<String>,
+    pub max_commit_message_bytes: Option<usize>,
+    pub body_wrap_columns: Option<usize>,
+    pub require_sign_off: bool,
+    pub generate_change_id: bool,
+    pub comment_char: char,
+    pub verify_pull_request_repo: bool,
+    pub collapse_test_plan: bool,
+    pub trailer_order: Vec<MessageSection>,
+    pub ticket_prefix_regex: Option<String>,
+    pub include_ticket_in_title: bool,
+    pub trailer_separator_lines: usize,
+    pub validate_reviewer_format: bool,
+    pub pr_body_footer: Option<String>,
+    pub extra_known_trailers: Vec<String>,
+    pub include_reviewers_in_merge_commit: bool,
+    pub github_title_max: usize,
+    pub truncate_github_title: bool,
+    pub preserve_section_whitespace: Vec<MessageSection>,
+    pub max_reviewers: Option<usize>,
+    pub output_symbols: OutputSymbols,
+    pub normalize_reviewer_case: bool,
+    pub scan_for_secrets: bool,
+    pub require_summary: bool,
+    pub footer_trailers: Vec<String>,
+    pub forbid_landing_work_in_progress: bool,
+    pub unquote_trailer_values: bool,
 }
 
 impl Config {
@@ -43,6 +195,81 @@ impl Config {
             branch_prefix,
             require_approval,
             require_test_plan,
+            trailer_case_sensitive_keys: false,
+            summary_strip_html_comments: false,
+            forbid_empty_summary_paragraphs: false,
+            reviewers_from_codeowners: false,
+            github_body_empty_placeholder: None,
+            max_trailer_value_length: None,
+            forbid_summary_headings: false,
+            summary_trim_trailing_blank_lines: true,
+            require_uniform_stack_reviewers: false,
+            pull_request_link_format: None,
+            cleanup_mode: CleanupMode::Whitespace,
+            placeholder_patterns: Vec::new(),
+            section_destinations: [
+                MessageSection::Title,
+                MessageSection::Summary,
+                MessageSection::TestPlan,
+                MessageSection::Reviewers,
+                MessageSection::ReviewedBy,
+                MessageSection::VersionBump,
+                MessageSection::DependsOn,
+                MessageSection::PullRequest,
+                MessageSection::CoAuthoredBy,
+                MessageSection::SignOffChain,
+                MessageSection::Closes,
+                MessageSection::ChangeId,
+                MessageSection::Ticket,
+            ]
+            .into_iter()
+            .map(|section| (section, SectionDestinations::default_for(section)))
+            .collect(),
+            suggested_title_max_length: 72,
+            accepted_trailer_separators: vec![":".to_string()],
+            reviewed_by_include_state: false,
+            subject_forbid_trailing_whitespace: false,
+            section_labels: BTreeMap::new(),
+            validate_version_bump: false,
+            require_reviewer_team: false,
+            require_test_plan_when_summary_nonempty: false,
+            title_max_words: None,
+            max_subject_length: None,
+            required_sections: Vec::new(),
+            warn_mixed_reviewer_prefix: false,
+            reviewed_by_must_be_requested: false,
+            normalize_on_parse: false,
+            one_entry_per_line_sections: Vec::new(),
+            forbid_body_duplicates_title: false,
+            close_issues_on_merge: true,
+            align_trailer_values: false,
+            footer_template: None,
+            max_commit_message_bytes: None,
+            body_wrap_columns: None,
+            require_sign_off: false,
+            generate_change_id: false,
+            comment_char: '#',
+            verify_pull_request_repo: false,
+            collapse_test_plan: false,
+            trailer_order: Vec::new(),
+            ticket_prefix_regex: None,
+            include_ticket_in_title: true,
+            trailer_separator_lines: 1,
+            validate_reviewer_format: false,
+            pr_body_footer: None,
+            extra_known_trailers: Vec::new(),
+            include_reviewers_in_merge_commit: true,
+            github_title_max: 256,
+            truncate_github_title: false,
+            preserve_section_whitespace: Vec::new(),
+            max_reviewers: None,
+            output_symbols: OutputSymbols::default(),
+            normalize_reviewer_case: false,
+            scan_for_secrets: false,
+            require_summary: false,
+            footer_trailers: Vec::new(),
+            forbid_landing_work_in_progress: true,
+            unquote_trailer_values: false,
         }
     }
 
@@ -147,6 +374,23 @@ mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
     use super::*;
 
+    #[test]
+    fn test_cleanup_mode_from_git_config() {
+        assert_eq!(
+            CleanupMode::from_git_config("default"),
+            Some(CleanupMode::Whitespace)
+        );
+        assert_eq!(
+            CleanupMode::from_git_config("strip"),
+            Some(CleanupMode::Strip)
+        );
+        assert_eq!(
+            CleanupMode::from_git_config("verbatim"),
+            Some(CleanupMode::Verbatim)
+        );
+        assert_eq!(CleanupMode::from_git_config("stirp"), None);
+    }
+
     fn config_factory() -> Config {
         crate::config::Config::new(
             "acme".into(),
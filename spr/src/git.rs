@@ -12,7 +12,9 @@ use crate::{
     error::{Error, Result, ResultExt},
     github::GitHubBranch,
     message::{
-        build_commit_message, parse_message, MessageSection, MessageSectionsMap,
+        build_commit_message, generate_change_id, parse_commit_message,
+        subject_has_trailing_whitespace, validate_pull_request_repo,
+        MessageSection, MessageSectionsMap,
     },
     utils::run_command,
 };
@@ -25,6 +27,9 @@ pub struct PreparedCommit {
     pub parent_oid: Oid,
     pub message: MessageSectionsMap,
     pub pull_request_number: Option<u64>,
+    /// Whether the first line of the original commit message ended in
+    /// whitespace, which parsing removes.
+    pub subject_has_trailing_whitespace: bool,
 }
 
 #[derive(Clone)]
@@ -36,16 +41,18 @@ pub struct Git {
 impl Git {
     pub fn new(repo: git2::Repository) -> Self {
         Self {
-            hooks: std::sync::Arc::new(std::sync::Mutex::new(git2_ext::hooks::Hooks::with_repo(&repo).unwrap())),
+            hooks: std::sync::Arc::new(std::sync::Mutex::new(
+                git2_ext::hooks::Hooks::with_repo(&repo).unwrap(),
+            )),
             repo: std::sync::Arc::new(std::sync::Mutex::new(repo)),
         }
     }
 
-    pub fn repo(&self) -> std::sync::MutexGuard<git2::Repository> {
+    pub fn repo(&self) -> std::sync::MutexGuard<'_, git2::Repository> {
         self.repo.lock().expect("poisoned mutex")
     }
 
-    fn hooks(&self) -> std::sync::MutexGuard<git2_ext::hooks::Hooks> {
+    fn hooks(&self) -> std::sync::MutexGuard<'_, git2_ext::hooks::Hooks> {
         self.hooks.lock().expect("poisoned mutex")
     }
 
@@ -71,6 +78,7 @@ impl Git {
 
     pub fn rewrite_commit_messages(
         &self,
+        config: &Config,
         commits: &mut [PreparedCommit],
         mut limit: Option<usize>,
     ) -> Result<()> {
@@ -88,7 +96,8 @@ impl Git {
         for prepared_commit in commits.iter_mut() {
             let commit = repo.find_commit(prepared_commit.oid)?;
             if limit != Some(0) {
-                message = build_commit_message(&prepared_commit.message);
+                message =
+                    build_commit_message(&prepared_commit.message, config);
                 if Some(&message[..]) != commit.message() {
                     updating = true;
                 }
@@ -309,7 +318,7 @@ impl Git {
 
         let parent_oid = commit.parent_id(0)?;
 
-        let message =
+        let raw_message =
             String::from_utf8_lossy(commit.message_bytes()).into_owned();
 
         let short_id =
@@ -317,8 +326,12 @@ impl Git {
         drop(commit);
         drop(repo);
 
-        let mut message = parse_message(&message, MessageSection::Title);
+        let subject_has_trailing_whitespace =
+            subject_has_trailing_whitespace(&raw_message);
+        let mut message =
+            parse_commit_message(&raw_message, config, Some(&short_id))?;
 
+        validate_pull_request_repo(&message, config)?;
         let pull_request_number = message
             .get(&MessageSection::PullRequest)
             .and_then(|text| config.parse_pull_request_field(text));
@@ -332,12 +345,26 @@ impl Git {
             message.remove(&MessageSection::PullRequest);
         }
 
+        if config.generate_change_id
+            && !message.contains_key(&MessageSection::ChangeId)
+        {
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default();
+            message.insert(
+                MessageSection::ChangeId,
+                generate_change_id(&raw_message, timestamp)?,
+            );
+        }
+
         Ok(PreparedCommit {
             oid,
             short_id,
             parent_oid,
             message,
             pull_request_number,
+            subject_has_trailing_whitespace,
         })
     }
 
@@ -381,6 +408,41 @@ impl Git {
         Ok(index.write_tree_to(&self.repo())?)
     }
 
+    pub fn get_changed_paths(&self, oid: Oid) -> Result<Vec<String>> {
+        let repo = self.repo();
+        let commit = repo.find_commit(oid)?;
+        let parent_tree = commit.parent(0)?.tree()?;
+        let diff = repo.diff_tree_to_tree(
+            Some(&parent_tree),
+            Some(&commit.tree()?),
+            None,
+        )?;
+
+        Ok(diff
+            .deltas()
+            .filter_map(|delta| {
+                delta.new_file().path().or_else(|| delta.old_file().path())
+            })
+            .map(|path| path.to_string_lossy().into_owned())
+            .collect())
+    }
+
+    pub fn get_file_content(
+        &self,
+        oid: Oid,
+        path: &str,
+    ) -> Result<Option<String>> {
+        let repo = self.repo();
+        let tree = repo.find_commit(oid)?.tree()?;
+        let entry = match tree.get_path(std::path::Path::new(path)) {
+            Ok(entry) => entry,
+            Err(_) => return Ok(None),
+        };
+        let blob = entry.to_object(&repo)?.peel_to_blob()?;
+
+        Ok(Some(String::from_utf8_lossy(blob.content()).into_owned()))
+    }
+
     pub fn get_tree_oid_for_commit(&self, oid: Oid) -> Result<Oid> {
         let tree_oid = self.repo().find_commit(oid)?.tree_id();
 
@@ -12,7 +12,10 @@ use crate::{
     error::{Error, Result, ResultExt},
     git::Git,
     message::{
-        build_github_body, parse_message, MessageSection, MessageSectionsMap,
+        build_github_body, expand_collapsed_test_plan, github_title,
+        parse_pull_request_body, render_reviewed_by_with_state,
+        split_depends_on_block, split_ticket_prefix, strip_pr_body_footer,
+        Approver, MessageSection, MessageSectionsMap,
     },
 };
 use std::collections::{HashMap, HashSet};
@@ -71,13 +74,14 @@ impl PullRequestUpdate {
         &mut self,
         pull_request: &PullRequest,
         message: &MessageSectionsMap,
+        config: &crate::config::Config,
     ) {
-        let title = message.get(&MessageSection::Title);
-        if title.is_some() && title != Some(&pull_request.title) {
-            self.title = title.cloned();
+        let title = github_title(message, config);
+        if title.is_some() && title.as_ref() != Some(&pull_request.title) {
+            self.title = title;
         }
 
-        let body = build_github_body(message);
+        let body = build_github_body(message, config);
         if pull_request.body.as_ref() != Some(&body) {
             self.body = Some(body);
         }
@@ -206,7 +210,22 @@ impl GitHub {
         let base_oid = git.resolve_reference(base.local())?;
         let head_oid = git.resolve_reference(head.local())?;
 
-        let mut sections = parse_message(&pr.body, MessageSection::Summary);
+        let (depends_on, body) = split_depends_on_block(&pr.body);
+        let body = strip_pr_body_footer(body, &config);
+        let body = expand_collapsed_test_plan(body, &config);
+        let mut sections = parse_pull_request_body(&body, &config)?;
+        if let Some(depends_on) = depends_on {
+            sections.insert(MessageSection::DependsOn, depends_on);
+        }
+
+        // Don't carry the placeholder for an empty description over into the
+        // commit message.
+        if config.github_body_empty_placeholder.is_some()
+            && sections.get(&MessageSection::Summary)
+                == config.github_body_empty_placeholder.as_ref()
+        {
+            sections.remove(&MessageSection::Summary);
+        }
 
         let title = pr.title.trim().to_string();
         sections.insert(
@@ -217,6 +236,7 @@ impl GitHub {
                 title
             },
         );
+        split_ticket_prefix(&mut sections, &config);
 
         sections.insert(
             MessageSection::PullRequest,
@@ -278,24 +298,23 @@ impl GitHub {
         );
 
         if review_status == Some(ReviewStatus::Approved) {
+            let mut approvers: Vec<Approver> = reviewers
+                .iter()
+                .filter(|(_, status)| {
+                    status == &&ReviewStatus::Approved
+                        || (config.reviewed_by_include_state
+                            && status == &&ReviewStatus::Rejected)
+                })
+                .map(|(login, status)| Approver {
+                    login: login.clone(),
+                    status: status.clone(),
+                })
+                .collect();
+            approvers.sort_by(|a, b| a.login.cmp(&b.login));
+
             sections.insert(
                 MessageSection::ReviewedBy,
-                reviewers
-                    .iter()
-                    .filter_map(|(k, v)| {
-                        if v == &ReviewStatus::Approved {
-                            Some(k)
-                        } else {
-                            None
-                        }
-                    })
-                    .fold(String::new(), |out, slug| {
-                        if out.is_empty() {
-                            slug.to_string()
-                        } else {
-                            format!("{}, {}", out, slug)
-                        }
-                    }),
+                render_reviewed_by_with_state(&approvers, &config),
             );
         }
 
@@ -332,13 +351,11 @@ impl GitHub {
         let number = octocrab::instance()
             .pulls(self.config.owner.clone(), self.config.repo.clone())
             .create(
-                message
-                    .get(&MessageSection::Title)
-                    .unwrap_or(&String::new()),
+                github_title(message, &self.config).unwrap_or_default(),
                 head_ref_name,
                 base_ref_name,
             )
-            .body(build_github_body(message))
+            .body(build_github_body(message, &self.config))
             .draft(Some(draft))
             .send()
             .await?
@@ -5,6 +5,7 @@
  * LICENSE file in the root directory of this source tree.
  */
 
+pub mod codeowners;
 pub mod commands;
 pub mod config;
 pub mod error;
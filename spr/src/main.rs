@@ -14,7 +14,7 @@ use reqwest::{self, header};
 use spr::{
     commands,
     error::{Error, Result},
-    output::output,
+    output::{output, OutputSymbols},
 };
 
 #[derive(Parser, Debug)]
@@ -83,6 +83,18 @@ pub enum OptionsError {
         "GitHub repository must be given as 'OWNER/REPO', but given value was '{0}'"
     )]
     InvalidRepository(String),
+    #[error("{0} must not be negative, but given value was {1}")]
+    NegativeNumber(String, i64),
+}
+
+/// Read a non-negative integer from the git configuration, if it is set.
+fn get_usize(git_config: &git2::Config, key: &str) -> Result<Option<usize>> {
+    match git_config.get_i64(key) {
+        Ok(value) => Ok(Some(usize::try_from(value).map_err(|_| {
+            OptionsError::NegativeNumber(key.to_string(), value)
+        })?)),
+        Err(_) => Ok(None),
+    }
 }
 
 pub async fn spr() -> Result<()> {
@@ -136,7 +148,7 @@ pub async fn spr() -> Result<()> {
         .ok()
         .unwrap_or(true);
 
-    let config = spr::config::Config::new(
+    let mut config = spr::config::Config::new(
         github_owner,
         github_repo,
         github_remote_name,
@@ -145,6 +157,287 @@ pub async fn spr() -> Result<()> {
         require_approval,
         require_test_plan,
     );
+    config.trailer_case_sensitive_keys = git_config
+        .get_bool("spr.trailerCaseSensitiveKeys")
+        .ok()
+        .unwrap_or(false);
+    config.summary_strip_html_comments = git_config
+        .get_bool("spr.summaryStripHtmlComments")
+        .ok()
+        .unwrap_or(false);
+    config.forbid_empty_summary_paragraphs = git_config
+        .get_bool("spr.forbidEmptySummaryParagraphs")
+        .ok()
+        .unwrap_or(false);
+    config.reviewers_from_codeowners = git_config
+        .get_bool("spr.reviewersFromCodeowners")
+        .ok()
+        .unwrap_or(false);
+    config.github_body_empty_placeholder =
+        git_config.get_string("spr.githubBodyEmptyPlaceholder").ok();
+    config.max_trailer_value_length =
+        get_usize(&git_config, "spr.maxTrailerValueLength")?;
+    config.forbid_summary_headings = git_config
+        .get_bool("spr.forbidSummaryHeadings")
+        .ok()
+        .unwrap_or(false);
+    config.summary_trim_trailing_blank_lines = git_config
+        .get_bool("spr.summaryTrimTrailingBlankLines")
+        .ok()
+        .unwrap_or(true);
+    config.require_uniform_stack_reviewers = git_config
+        .get_bool("spr.requireUniformStackReviewers")
+        .ok()
+        .unwrap_or(false);
+    config.pull_request_link_format =
+        git_config.get_string("spr.pullRequestLinkFormat").ok();
+    config.footer_template = git_config.get_string("spr.footerTemplate").ok();
+    if let Some(comment_char) = git_config
+        .get_string("core.commentChar")
+        .ok()
+        .filter(|value| value != "auto")
+        .and_then(|value| value.chars().next())
+    {
+        config.comment_char = comment_char;
+    }
+    config.suggested_title_max_length =
+        get_usize(&git_config, "spr.suggestedTitleMaxLength")?.unwrap_or(72);
+    config.reviewed_by_include_state = git_config
+        .get_bool("spr.reviewedByIncludeState")
+        .ok()
+        .unwrap_or(false);
+    config.subject_forbid_trailing_whitespace = git_config
+        .get_bool("spr.subjectForbidTrailingWhitespace")
+        .ok()
+        .unwrap_or(false);
+    config.validate_version_bump = git_config
+        .get_bool("spr.validateVersionBump")
+        .ok()
+        .unwrap_or(false);
+    config.require_reviewer_team = git_config
+        .get_bool("spr.requireReviewerTeam")
+        .ok()
+        .unwrap_or(false);
+    config.require_test_plan_when_summary_nonempty = git_config
+        .get_bool("spr.requireTestPlanWhenSummaryNonempty")
+        .ok()
+        .unwrap_or(false);
+    config.title_max_words = get_usize(&git_config, "spr.titleMaxWords")?;
+    config.max_subject_length = get_usize(&git_config, "spr.maxSubjectLength")?;
+    config.max_commit_message_bytes =
+        get_usize(&git_config, "spr.maxCommitMessageBytes")?;
+    config.body_wrap_columns = get_usize(&git_config, "spr.bodyWrapColumns")?;
+    config.require_sign_off = git_config
+        .get_bool("spr.requireSignOff")
+        .ok()
+        .unwrap_or(false);
+    config.generate_change_id = git_config
+        .get_bool("spr.generateChangeId")
+        .ok()
+        .unwrap_or(false);
+    config.verify_pull_request_repo = git_config
+        .get_bool("spr.verifyPullRequestRepo")
+        .ok()
+        .unwrap_or(false);
+    config.collapse_test_plan = git_config
+        .get_bool("spr.collapseTestPlan")
+        .ok()
+        .unwrap_or(false);
+    config.ticket_prefix_regex =
+        git_config.get_string("spr.ticketPrefixRegex").ok();
+    config.include_ticket_in_title = git_config
+        .get_bool("spr.includeTicketInTitle")
+        .ok()
+        .unwrap_or(true);
+    config.trailer_separator_lines =
+        get_usize(&git_config, "spr.trailerSeparatorLines")?.unwrap_or(1);
+    config.validate_reviewer_format = git_config
+        .get_bool("spr.validateReviewerFormat")
+        .ok()
+        .unwrap_or(false);
+    config.pr_body_footer = git_config.get_string("spr.prBodyFooter").ok();
+    config.include_reviewers_in_merge_commit = git_config
+        .get_bool("spr.includeReviewersInMergeCommit")
+        .ok()
+        .unwrap_or(true);
+    config.github_title_max =
+        get_usize(&git_config, "spr.githubTitleMax")?.unwrap_or(256);
+    config.truncate_github_title = git_config
+        .get_bool("spr.truncateGithubTitle")
+        .ok()
+        .unwrap_or(false);
+    config.max_reviewers = get_usize(&git_config, "spr.maxReviewers")?;
+    if let Ok(symbol) = git_config.get_string("spr.errorSymbol") {
+        config.output_symbols.error = symbol;
+    }
+    if let Ok(symbol) = git_config.get_string("spr.warningSymbol") {
+        config.output_symbols.warning = symbol;
+    }
+    if let Ok(symbol) = git_config.get_string("spr.infoSymbol") {
+        config.output_symbols.info = symbol;
+    }
+    if let Ok(value) = git_config.get_string("commit.cleanup") {
+        match spr::config::CleanupMode::from_git_config(&value) {
+            Some(mode) => config.cleanup_mode = mode,
+            None => output(
+                &config.output_symbols.warning,
+                &format!(
+                    "Ignoring unknown commit.cleanup value '{}'; using \
+                     'whitespace'",
+                    value
+                ),
+            )?,
+        }
+    }
+    config.normalize_reviewer_case = git_config
+        .get_bool("spr.normalizeReviewerCase")
+        .ok()
+        .unwrap_or(false);
+    config.scan_for_secrets = git_config
+        .get_bool("spr.scanForSecrets")
+        .ok()
+        .unwrap_or(false);
+    config.require_summary = git_config
+        .get_bool("spr.requireSummary")
+        .ok()
+        .unwrap_or(false);
+    config.forbid_landing_work_in_progress = git_config
+        .get_bool("spr.forbidLandingWorkInProgress")
+        .ok()
+        .unwrap_or(true);
+    config.unquote_trailer_values = git_config
+        .get_bool("spr.unquoteTrailerValues")
+        .ok()
+        .unwrap_or(false);
+    config.warn_mixed_reviewer_prefix = git_config
+        .get_bool("spr.warnMixedReviewerPrefix")
+        .ok()
+        .unwrap_or(false);
+    config.reviewed_by_must_be_requested = git_config
+        .get_bool("spr.reviewedByMustBeRequested")
+        .ok()
+        .unwrap_or(false);
+    config.normalize_on_parse = git_config
+        .get_bool("spr.normalizeOnParse")
+        .ok()
+        .unwrap_or(false);
+    config.forbid_body_duplicates_title = git_config
+        .get_bool("spr.forbidBodyDuplicatesTitle")
+        .ok()
+        .unwrap_or(false);
+    config.close_issues_on_merge = git_config
+        .get_bool("spr.closeIssuesOnMerge")
+        .ok()
+        .unwrap_or(true);
+    config.align_trailer_values = git_config
+        .get_bool("spr.alignTrailerValues")
+        .ok()
+        .unwrap_or(false);
+    if let Ok(sections) = git_config.get_string("spr.requiredSections") {
+        config.required_sections = sections
+            .split(',')
+            .filter_map(|label| {
+                spr::message::message_section_by_label(label.trim(), &config)
+            })
+            .collect();
+    }
+    if let Ok(sections) = git_config.get_string("spr.oneEntryPerLineSections") {
+        config.one_entry_per_line_sections = sections
+            .split(',')
+            .filter_map(|label| {
+                spr::message::message_section_by_label(label.trim(), &config)
+            })
+            .collect();
+    }
+    if let Ok(labels) = git_config.get_string("spr.extraKnownTrailers") {
+        config.extra_known_trailers = labels
+            .split(',')
+            .map(|label| label.trim().to_string())
+            .filter(|label| !label.is_empty())
+            .collect();
+    }
+    if let Ok(sections) = git_config.get_string("spr.preserveSectionWhitespace")
+    {
+        config.preserve_section_whitespace = sections
+            .split(',')
+            .filter_map(|label| {
+                spr::message::message_section_by_label(label.trim(), &config)
+            })
+            .collect();
+    }
+    if let Ok(labels) = git_config.get_string("spr.footerTrailers") {
+        config.footer_trailers = labels
+            .split(',')
+            .map(|label| label.trim().to_string())
+            .filter(|label| !label.is_empty())
+            .collect();
+    }
+    if let Ok(sections) = git_config.get_string("spr.trailerOrder") {
+        config.trailer_order = sections
+            .split(',')
+            .filter_map(|label| {
+                spr::message::message_section_by_label(label.trim(), &config)
+            })
+            .collect();
+        if config.trailer_order.iter().any(|section| {
+            matches!(
+                section,
+                spr::message::MessageSection::Title
+                    | spr::message::MessageSection::Summary
+            )
+        }) {
+            return Err(Error::new(
+                "spr.trailerOrder must not list the title or summary",
+            ));
+        }
+    }
+    for (key, section) in [
+        ("spr.testPlanLabel", spr::message::MessageSection::TestPlan),
+        (
+            "spr.reviewersLabel",
+            spr::message::MessageSection::Reviewers,
+        ),
+        (
+            "spr.reviewedByLabel",
+            spr::message::MessageSection::ReviewedBy,
+        ),
+        (
+            "spr.pullRequestLabel",
+            spr::message::MessageSection::PullRequest,
+        ),
+    ] {
+        if let Ok(label) = git_config.get_string(key) {
+            config.section_labels.insert(section, label);
+        }
+    }
+    if let Ok(separators) =
+        git_config.get_string("spr.acceptedTrailerSeparators")
+    {
+        config.accepted_trailer_separators =
+            separators.split_whitespace().map(String::from).collect();
+    } else if let Ok(separators) = git_config.get_string("trailer.separators") {
+        // Git's setting lists single-character separators, as in `:=`.
+        config.accepted_trailer_separators = separators
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(String::from)
+            .collect();
+    }
+    // spr writes `:` itself, so it must always be able to read it back.
+    if !config
+        .accepted_trailer_separators
+        .iter()
+        .any(|separator| separator == ":")
+    {
+        config.accepted_trailer_separators.push(":".to_string());
+    }
+    if let Ok(entries) = git_config.multivar("spr.placeholderPattern", None) {
+        entries.for_each(|entry| {
+            if let Some(value) = entry.value() {
+                config.placeholder_patterns.push(value.to_string());
+            }
+        })?;
+    }
 
     let git = spr::git::Git::new(repo);
 
@@ -207,11 +500,24 @@ pub async fn spr() -> Result<()> {
     Ok::<_, Error>(())
 }
 
+/// The symbol for errors, as configured in `spr.errorSymbol`. Errors may
+/// occur before the configuration is read in full, so it is looked up on
+/// its own.
+fn error_symbol() -> String {
+    std::env::current_dir()
+        .ok()
+        .and_then(|dir| git2::Repository::discover(dir).ok())
+        .and_then(|repo| repo.config().ok())
+        .and_then(|config| config.get_string("spr.errorSymbol").ok())
+        .unwrap_or_else(|| OutputSymbols::default().error)
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     if let Err(error) = spr().await {
+        let symbol = error_symbol();
         for message in error.messages() {
-            output("🛑", message)?;
+            output(&symbol, message)?;
         }
         std::process::exit(1);
     }
@@ -7,21 +7,91 @@
 
 use crate::{error::Result, git::PreparedCommit, message::MessageSection};
 
+/// The icons shown by `output` for errors, warnings and information, which
+/// can be replaced with e.g. plain ASCII markers like `[!]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OutputSymbols {
+    pub error: String,
+    pub warning: String,
+    pub info: String,
+}
+
+impl Default for OutputSymbols {
+    fn default() -> Self {
+        Self {
+            error: "💔".into(),
+            warning: "⚠️".into(),
+            info: "ℹ️".into(),
+        }
+    }
+}
+
 pub fn output(icon: &str, text: &str) -> Result<()> {
     let term = console::Term::stdout();
 
+    term.write_line(&format_output(
+        icon,
+        text,
+        use_plain_output(&term),
+        term.size().1 as usize,
+    ))?;
+    Ok(())
+}
+
+/// Whether output to the given terminal should be plain text, without emoji
+/// or ANSI styling. See `plain_output_wanted`.
+fn use_plain_output(term: &console::Term) -> bool {
+    plain_output_wanted(
+        term.is_term(),
+        std::env::var_os("NO_COLOR"),
+        std::env::var_os("SPR_NO_EMOJI"),
+    )
+}
+
+/// Whether output should be plain text: when stdout is not a terminal, or
+/// `NO_COLOR` or `SPR_NO_EMOJI` is set (to any value).
+fn plain_output_wanted(
+    is_term: bool,
+    no_color: Option<std::ffi::OsString>,
+    no_emoji: Option<std::ffi::OsString>,
+) -> bool {
+    !is_term || no_color.is_some() || no_emoji.is_some()
+}
+
+/// The word that replaces an icon in plain output, if any.
+fn plain_prefix(icon: &str) -> Option<&'static str> {
+    match icon.trim() {
+        "💔" | "❌" | "❗" | "🛑" => Some("ERROR:"),
+        "⚠️" => Some("WARNING:"),
+        "💡" => Some("HINT:"),
+        _ => None,
+    }
+}
+
+fn format_output(icon: &str, text: &str, plain: bool, width: usize) -> String {
+    if plain {
+        let text = console::strip_ansi_codes(text.trim());
+        return match plain_prefix(icon) {
+            Some(prefix) => format!("{} {}", prefix, text),
+            // Configured ASCII markers are kept.
+            None if !icon.trim().is_empty() && icon.trim().is_ascii() => {
+                format!("{} {}", icon.trim(), text)
+            }
+            None => text.into_owned(),
+        };
+    }
+
     let bullet = format!("  {}  ", icon);
     let indent = console::measure_text_width(&bullet);
     let indent_string = " ".repeat(indent);
-    let options = textwrap::Options::new((term.size().1 as usize) - indent * 2)
+    let options = textwrap::Options::new(width - indent * 2)
         .initial_indent(&bullet)
         .subsequent_indent(&indent_string)
         .break_words(false)
         .word_separator(textwrap::WordSeparator::AsciiSpace)
         .word_splitter(textwrap::WordSplitter::NoHyphenation);
 
-    term.write_line(&textwrap::wrap(text.trim(), &options).join("\n"))?;
-    Ok(())
+    textwrap::wrap(text.trim(), &options).join("\n")
 }
 
 pub fn write_commit_title(prepared_commit: &PreparedCommit) -> Result<()> {
@@ -40,3 +110,57 @@ pub fn write_commit_title(prepared_commit: &PreparedCommit) -> Result<()> {
     ))?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn test_plain_output_wanted() {
+        assert!(!plain_output_wanted(true, None, None));
+        assert!(plain_output_wanted(false, None, None));
+        assert!(plain_output_wanted(true, Some("1".into()), None));
+        assert!(plain_output_wanted(true, None, Some("".into())));
+    }
+
+    #[test]
+    fn test_format_output_plain() {
+        assert_eq!(
+            format_output(
+                "💔",
+                "Commit message does not have a title!",
+                true,
+                80
+            ),
+            "ERROR: Commit message does not have a title!"
+        );
+        assert_eq!(
+            format_output("🛫", "\u{1b}[33mLanding\u{1b}[0m", true, 80),
+            "Landing"
+        );
+        assert_eq!(
+            format_output("💔", "Oops", false, 80),
+            "  💔  Oops".to_string()
+        );
+    }
+
+    #[test]
+    fn test_format_output_custom_symbols() {
+        let symbols = OutputSymbols {
+            error: "[!]".into(),
+            warning: "[?]".into(),
+            info: "[i]".into(),
+        };
+
+        assert_eq!(
+            format_output(&symbols.error, "Oops", false, 80),
+            "  [!]  Oops"
+        );
+        assert_eq!(
+            format_output(&symbols.warning, "Careful", true, 80),
+            "[?] Careful"
+        );
+        assert_eq!(format_output("  ", "Detail", true, 80), "Detail");
+    }
+}
@@ -38,6 +38,92 @@ pub fn parse_name_list(text: &str) -> Vec<String> {
         .collect()
 }
 
+/// Split a Reviewers section into its entries, exactly as written: only
+/// commas separate entries, so `@org/team` references stay intact.
+pub fn split_reviewers(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|entry| entry.trim())
+        .filter(|entry| !entry.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Parse the names in a Reviewers section. A leading `@`, as in `@alice`, is
+/// dropped, GitHub's `@org/team` team references become `#team`, and names
+/// that appear more than once (ignoring case) are only returned once.
+pub fn parse_reviewers(text: &str) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+
+    parse_name_list(text)
+        .into_iter()
+        .map(|name| match name.strip_prefix('@') {
+            Some(handle) => match handle.split_once('/') {
+                Some((_org, team)) => format!("#{}", team),
+                None => handle.to_string(),
+            },
+            None => name,
+        })
+        .filter(|name| !name.is_empty() && seen.insert(name.to_lowercase()))
+        .collect()
+}
+
+/// Whether a reviewer is named both with and without a leading `@`, as in
+/// `@alice, alice`.
+pub fn has_mixed_reviewer_prefix(text: &str) -> bool {
+    let names = parse_name_list(text);
+    names.iter().any(|name| {
+        name.strip_prefix('@').is_some_and(|bare| {
+            names.iter().any(|other| other.eq_ignore_ascii_case(bare))
+        })
+    })
+}
+
+/// Remove matching quotes around a value, as in `"Alice" <alice@x>` or
+/// `'bob'`. A trailing `<email>` part is kept as it is.
+pub fn unquote_trailer_value(value: &str) -> String {
+    let value = value.trim();
+    let (name, email) = match value.rfind('<') {
+        Some(pos) if value.ends_with('>') => {
+            (value[..pos].trim_end(), &value[pos..])
+        }
+        _ => (value, ""),
+    };
+
+    let name = ['"', '\'']
+        .iter()
+        .find_map(|quote| name.strip_prefix(*quote)?.strip_suffix(*quote))
+        .unwrap_or(name);
+
+    if email.is_empty() {
+        name.to_string()
+    } else if name.is_empty() {
+        email.to_string()
+    } else {
+        format!("{} {}", name, email)
+    }
+}
+
+/// The number of single-character insertions, deletions and substitutions
+/// needed to turn one string into the other.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(
+                substitution.min(previous[j + 1] + 1).min(current[j] + 1),
+            );
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
 pub fn remove_all_parens(text: &str) -> String {
     lazy_regex::regex!(r#"[()]"#).replace_all(text, "").into()
 }
@@ -123,4 +209,56 @@ mod tests {
             expected
         );
     }
+
+    #[test]
+    fn test_unquote_trailer_value() {
+        assert_eq!(
+            unquote_trailer_value("\"Alice\" <alice@x>"),
+            "Alice <alice@x>"
+        );
+        assert_eq!(unquote_trailer_value("\"Alice Smith\""), "Alice Smith");
+        assert_eq!(unquote_trailer_value("'bob'"), "bob");
+        assert_eq!(unquote_trailer_value(" carol <c@x> "), "carol <c@x>");
+        assert_eq!(unquote_trailer_value("\"dave'"), "\"dave'");
+        assert_eq!(unquote_trailer_value("\""), "\"");
+    }
+
+    #[test]
+    fn test_parse_reviewers() {
+        assert_eq!(parse_reviewers("@alice, alice"), vec!["alice".to_string()]);
+        assert_eq!(
+            parse_reviewers("@alice, Bob, bob (Bob B), #team"),
+            vec!["alice".to_string(), "Bob".to_string(), "#team".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_has_mixed_reviewer_prefix() {
+        assert!(has_mixed_reviewer_prefix("@alice, alice"));
+        assert!(has_mixed_reviewer_prefix("Alice, bob, @alice"));
+        assert!(!has_mixed_reviewer_prefix("@alice, @bob"));
+        assert!(!has_mixed_reviewer_prefix("alice, bob"));
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("reviewers", "reviewers"), 0);
+        assert_eq!(edit_distance("reviewer", "reviewers"), 1);
+        assert_eq!(edit_distance("reviewdby", "reviewedby"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_split_reviewers() {
+        assert_eq!(
+            split_reviewers("alice, @acme/backend,bob ,"),
+            vec!["alice", "@acme/backend", "bob"]
+        );
+        assert_eq!(
+            parse_reviewers("alice, @acme/backend, bob, #backend"),
+            vec!["alice", "#backend", "bob"]
+        );
+    }
 }